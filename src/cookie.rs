@@ -0,0 +1,239 @@
+use std::time::{Duration, SystemTime};
+
+use crate::{parse_http_date, Url};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Cookie {
+    name: String,
+    value: String,
+    domain: String,
+    // without a Domain attribute the cookie only goes back to the exact host
+    host_only: bool,
+    path: String,
+    expires: Option<SystemTime>,
+    secure: bool,
+}
+
+impl Cookie {
+    fn parse(url: &Url, set_cookie: &str, now: SystemTime) -> Option<Cookie> {
+        let Url::Web { host, path, .. } = url else {
+            return None;
+        };
+        let mut attributes = set_cookie.split(';');
+        let (name, value) = attributes.next()?.split_once('=')?;
+        let name = name.trim();
+        if name.is_empty() {
+            return None;
+        }
+
+        let mut cookie = Cookie {
+            name: name.to_string(),
+            value: value.trim().to_string(),
+            domain: host.to_string(),
+            host_only: true,
+            path: default_path(path),
+            expires: None,
+            secure: false,
+        };
+        let mut max_age = None;
+        for attribute in attributes {
+            let (key, value) = match attribute.split_once('=') {
+                Some((key, value)) => (key.trim(), value.trim()),
+                None => (attribute.trim(), ""),
+            };
+            match key.to_lowercase().as_str() {
+                "domain" if !value.is_empty() => {
+                    let domain = value.trim_start_matches('.').to_lowercase();
+                    // a host may only set cookies for itself or a parent domain
+                    if !domain_matches(host, &domain) {
+                        return None;
+                    }
+                    cookie.domain = domain;
+                    cookie.host_only = false;
+                }
+                "path" if value.starts_with('/') => cookie.path = value.to_string(),
+                "expires" => cookie.expires = parse_http_date(value).or(cookie.expires),
+                "max-age" => max_age = value.parse::<i64>().ok(),
+                "secure" => cookie.secure = true,
+                _ => (),
+            }
+        }
+        // max-age wins over expires when both are present
+        if let Some(max_age) = max_age {
+            cookie.expires = match max_age {
+                age if age <= 0 => Some(SystemTime::UNIX_EPOCH),
+                age => Some(now + Duration::from_secs(age as u64)),
+            };
+        }
+        Some(cookie)
+    }
+
+    fn is_expired(&self, now: SystemTime) -> bool {
+        self.expires.is_some_and(|expires| expires <= now)
+    }
+
+    fn matches(&self, url: &Url) -> bool {
+        let Url::Web {
+            scheme, host, path, ..
+        } = url
+        else {
+            return false;
+        };
+        let host_matches = match self.host_only {
+            true => *host == self.domain,
+            false => domain_matches(host, &self.domain),
+        };
+        host_matches && path_matches(path, &self.path) && (!self.secure || scheme == "https")
+    }
+}
+
+fn domain_matches(host: &str, domain: &str) -> bool {
+    host == domain || host.ends_with(&format!(".{}", domain))
+}
+
+fn path_matches(request_path: &str, cookie_path: &str) -> bool {
+    let request_path = request_path_only(request_path);
+    request_path == cookie_path
+        || (request_path.starts_with(cookie_path)
+            && (cookie_path.ends_with('/') || request_path[cookie_path.len()..].starts_with('/')))
+}
+
+// the directory of the request path, used when Set-Cookie has no Path
+fn default_path(path: &str) -> String {
+    match request_path_only(path).rsplit_once('/') {
+        Some((directory, _)) if !directory.is_empty() => directory.to_string(),
+        _ => "/".to_string(),
+    }
+}
+
+fn request_path_only(path: &str) -> &str {
+    match path.split(['?', '#']).next() {
+        Some(path) if !path.is_empty() => path,
+        _ => "/",
+    }
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct CookieJar {
+    cookies: Vec<Cookie>,
+}
+
+impl CookieJar {
+    pub(crate) fn store(&mut self, url: &Url, set_cookie: &str) {
+        let now = SystemTime::now();
+        let Some(cookie) = Cookie::parse(url, set_cookie, now) else {
+            return;
+        };
+        self.cookies.retain(|existing| {
+            !(existing.name == cookie.name
+                && existing.domain == cookie.domain
+                && existing.path == cookie.path)
+        });
+        if !cookie.is_expired(now) {
+            self.cookies.push(cookie);
+        }
+    }
+
+    // the value of a Cookie header for a request to url, if any cookies apply
+    pub(crate) fn header_for(&mut self, url: &Url) -> Option<String> {
+        let now = SystemTime::now();
+        self.cookies.retain(|cookie| !cookie.is_expired(now));
+        let pairs: Vec<String> = self
+            .cookies
+            .iter()
+            .filter(|cookie| cookie.matches(url))
+            .map(|cookie| format!("{}={}", cookie.name, cookie.value))
+            .collect();
+        match pairs.is_empty() {
+            true => None,
+            false => Some(pairs.join("; ")),
+        }
+    }
+
+    #[cfg(test)]
+    fn len(&self) -> usize {
+        self.cookies.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn host_only_cookie() {
+        let mut jar = CookieJar::default();
+        jar.store(&Url::new("http://example.org/login"), "session=abc");
+
+        let header = jar.header_for(&Url::new("http://example.org/account"));
+        assert_eq!(header, Some("session=abc".to_string()));
+        assert_eq!(jar.header_for(&Url::new("http://www.example.org/")), None);
+        assert_eq!(jar.header_for(&Url::new("http://example.com/")), None);
+    }
+
+    #[test]
+    fn domain_cookie() {
+        let mut jar = CookieJar::default();
+        jar.store(
+            &Url::new("http://www.example.org/"),
+            "id=1; Domain=.example.org",
+        );
+        jar.store(
+            &Url::new("http://www.example.org/"),
+            "evil=1; Domain=example.com",
+        );
+
+        let header = jar.header_for(&Url::new("http://api.example.org/"));
+        assert_eq!(header, Some("id=1".to_string()));
+        assert_eq!(jar.len(), 1);
+    }
+
+    #[test]
+    fn path_cookie() {
+        let mut jar = CookieJar::default();
+        jar.store(&Url::new("http://example.org/docs/index.html"), "a=1");
+        jar.store(&Url::new("http://example.org/"), "b=2; Path=/api");
+
+        let docs = jar.header_for(&Url::new("http://example.org/docs/page?x=1"));
+        assert_eq!(docs, Some("a=1".to_string()));
+        let api = jar.header_for(&Url::new("http://example.org/api/users"));
+        assert_eq!(api, Some("b=2".to_string()));
+        assert_eq!(jar.header_for(&Url::new("http://example.org/apis")), None);
+    }
+
+    #[test]
+    fn secure_cookie() {
+        let mut jar = CookieJar::default();
+        jar.store(&Url::new("https://example.org/"), "token=t; Secure");
+
+        assert_eq!(jar.header_for(&Url::new("http://example.org/")), None);
+        let header = jar.header_for(&Url::new("https://example.org/"));
+        assert_eq!(header, Some("token=t".to_string()));
+    }
+
+    #[test]
+    fn expired_cookies_are_dropped() {
+        let url = Url::new("http://example.org/");
+        let mut jar = CookieJar::default();
+        jar.store(&url, "old=1; Expires=Thu, 01 Jan 1970 00:00:10 GMT");
+        assert_eq!(jar.len(), 0);
+
+        jar.store(
+            &url,
+            "keep=1; Max-Age=3600; Expires=Thu, 01 Jan 1970 00:00:10 GMT",
+        );
+        jar.store(&url, "gone=1; Max-Age=3600");
+        jar.store(&url, "gone=1; Max-Age=0");
+        assert_eq!(jar.header_for(&url), Some("keep=1".to_string()));
+    }
+
+    #[test]
+    fn replaces_existing_cookie() {
+        let url = Url::new("http://example.org/");
+        let mut jar = CookieJar::default();
+        jar.store(&url, "session=abc");
+        jar.store(&url, "session=def");
+        assert_eq!(jar.header_for(&url), Some("session=def".to_string()));
+    }
+}
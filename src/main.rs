@@ -1,14 +1,93 @@
 use std::env;
+use std::io::Write;
 
-use goat::Url;
+use goat::{Client, Request, Url};
+
+const USAGE: &str =
+    "usage: goat [-X <method>] [-H <header: value>]... [--show-headers] [--dry-run] <url>";
+
+struct Args {
+    method: String,
+    headers: Vec<(String, String)>,
+    show_headers: bool,
+    dry_run: bool,
+    url: String,
+}
+
+fn parse_args(args: &[String]) -> Option<Args> {
+    let mut method = "GET".to_string();
+    let mut headers = Vec::new();
+    let mut show_headers = false;
+    let mut dry_run = false;
+    let mut url = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-X" => method = args.next()?.to_string(),
+            "-H" => {
+                let (name, value) = args.next()?.split_once(':')?;
+                headers.push((name.trim().to_string(), value.trim().to_string()));
+            }
+            "--show-headers" => show_headers = true,
+            "--dry-run" => dry_run = true,
+            flag if flag.starts_with('-') => return None,
+            _ if url.is_some() => return None,
+            _ => url = Some(arg.to_string()),
+        }
+    }
+
+    Some(Args {
+        method,
+        headers,
+        show_headers,
+        dry_run,
+        url: url?,
+    })
+}
+
+fn fail(message: impl std::fmt::Display) -> ! {
+    eprintln!("goat: {}", message);
+    std::process::exit(1);
+}
 
 fn main() {
-    let args: Vec<String> = env::args().collect();
-    if args.len() == 2 {
-        let url = Url::new(&args[1]);
-        println!("url: {}", url);
-    } else {
-        println!("usage: goat <url>");
+    let args: Vec<String> = env::args().skip(1).collect();
+    let Some(args) = parse_args(&args) else {
+        println!("{}", USAGE);
         std::process::exit(1);
+    };
+
+    let url = Url::try_new(&args.url).unwrap_or_else(|err| fail(err));
+    let mut request = Request::new(&args.method, url);
+    for (name, value) in &args.headers {
+        request = request.header(name, value);
+    }
+
+    let client = Client::new();
+    let mut stdout = std::io::stdout();
+    if args.dry_run {
+        // print what would be sent instead of sending it
+        let bytes = client
+            .request_bytes(&request)
+            .unwrap_or_else(|err| fail(err));
+        stdout.write_all(&bytes).unwrap();
+        return;
+    }
+
+    let response = client.send(&request).unwrap_or_else(|err| fail(err));
+    if args.show_headers {
+        println!(
+            "{} {} {}",
+            response.version(),
+            response.status(),
+            response.explanation()
+        );
+        for (name, value) in response.header_lines() {
+            println!("{}: {}", name, value);
+        }
+        println!();
     }
+    let body = response.body().unwrap_or_default();
+    stdout.write_all(body).unwrap();
 }